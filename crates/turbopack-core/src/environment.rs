@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use turbo_tasks::trace::TraceRawVcs;
+
+/// The chunk loading mechanism targeted by a chunking context's output,
+/// which determines which platform-specific runtime is embedded into
+/// evaluated chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
+pub enum ChunkLoading {
+    /// No chunk loading is performed; the caller is responsible for
+    /// evaluating chunks in the right order.
+    None,
+    /// Chunk loading is performed via Node.js' `require`.
+    NodeJs,
+    /// Chunk loading is performed via DOM `<script>` injection.
+    Dom,
+    /// Chunk loading is performed via `importScripts()`, for execution
+    /// inside a dedicated or shared Worker scope where `document` is
+    /// unavailable.
+    Worker,
+}
+
+impl std::fmt::Display for ChunkLoading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ChunkLoading::None => "none",
+            ChunkLoading::NodeJs => "node.js",
+            ChunkLoading::Dom => "dom",
+            ChunkLoading::Worker => "worker",
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use std::io::Write;
+
+use anyhow::{bail, Result};
+use indoc::writedoc;
+use turbo_tasks::{primitives::StringVc, TryJoinIterExt, ValueToString, ValueToStringVc};
+use turbo_tasks_fs::{embed_file, FileContent};
+use turbopack_core::{
+    chunk::{ChunkGroupVc, ChunkingContextVc},
+    code_builder::{CodeBuilder, CodeVc},
+    environment::ChunkLoading,
+    ident::AssetIdentVc,
+    reference::AssetReferencesVc,
+};
+
+use super::{EcmascriptChunkRuntime, EcmascriptChunkRuntimeVc};
+use crate::{chunk::EcmascriptChunkVc, utils::StringifyJs};
+
+/// The runtime for a production build EcmaScript chunk.
+#[turbo_tasks::value(shared)]
+pub struct EcmascriptBuildChunkRuntime {
+    chunking_context: ChunkingContextVc,
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptBuildChunkRuntimeVc {
+    #[turbo_tasks::function]
+    pub fn new(chunking_context: ChunkingContextVc) -> Self {
+        EcmascriptBuildChunkRuntime { chunking_context }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for EcmascriptBuildChunkRuntime {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<StringVc> {
+        Ok(StringVc::cell(format!("Ecmascript Build Runtime")))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkRuntime for EcmascriptBuildChunkRuntime {
+    #[turbo_tasks::function]
+    fn decorate_asset_ident(&self, ident: AssetIdentVc) -> AssetIdentVc {
+        // Build chunks aren't registered with a chunk list, so there's
+        // nothing to decorate the ident with.
+        ident
+    }
+
+    #[turbo_tasks::function]
+    fn with_chunk_group(&self, _chunk_group: ChunkGroupVc) -> EcmascriptBuildChunkRuntimeVc {
+        // Build runtimes don't track a chunk group.
+        EcmascriptBuildChunkRuntimeVc::cell(EcmascriptBuildChunkRuntime {
+            chunking_context: self.chunking_context,
+        })
+    }
+
+    #[turbo_tasks::function]
+    fn references(&self, _origin_chunk: EcmascriptChunkVc) -> AssetReferencesVc {
+        AssetReferencesVc::cell(vec![])
+    }
+
+    #[turbo_tasks::function]
+    async fn params(&self, origin_chunk: EcmascriptChunkVc) -> Result<CodeVc> {
+        let runtime_module_ids = origin_chunk
+            .await?
+            .main_entries
+            .await?
+            .iter()
+            .map(|entry| entry.as_chunk_item(self.chunking_context).id())
+            .try_join()
+            .await?;
+
+        let mut code = CodeBuilder::default();
+
+        // Build params are a self-executing entry: the modules this chunk
+        // needs are instantiated directly here, rather than registered in a
+        // params object for the dev runtime to pick up later.
+        writedoc!(
+            code,
+            r#"
+                (() => {{
+                    for (const id of {}) {{
+                        __turbopack_instantiate__(id);
+                    }}
+                }})();
+            "#,
+            StringifyJs::new_pretty(&runtime_module_ids),
+        )?;
+
+        Ok(CodeVc::cell(code.build()))
+    }
+
+    #[turbo_tasks::function]
+    async fn code(&self) -> Result<CodeVc> {
+        let mut code = CodeBuilder::default();
+
+        // Build chunks execute immediately on load, so unlike the dev
+        // runtime there's no need to guard on a pending-chunk array.
+        writedoc!(
+            code,
+            r#"
+                (() => {{
+            "#,
+        )?;
+
+        let specific_runtime_code =
+            match &*self.chunking_context.environment().chunk_loading().await? {
+                ChunkLoading::None => embed_file!("js/src/runtime.none.js").await?,
+                ChunkLoading::NodeJs => embed_file!("js/src/runtime.nodejs.js").await?,
+                ChunkLoading::Dom => embed_file!("js/src/runtime.dom.js").await?,
+                ChunkLoading::Worker => embed_file!("js/src/runtime.worker.js").await?,
+            };
+
+        match &*specific_runtime_code {
+            FileContent::NotFound => bail!("specific runtime code is not found"),
+            FileContent::Content(file) => code.push_source(file.content(), None),
+        };
+
+        let shared_runtime_code = embed_file!("js/src/runtime.js").await?;
+
+        match &*shared_runtime_code {
+            FileContent::NotFound => bail!("shared runtime code is not found"),
+            FileContent::Content(file) => code.push_source(file.content(), None),
+        };
+
+        writedoc!(
+            code,
+            r#"
+                }})();
+            "#
+        )?;
+
+        Ok(CodeVc::cell(code.build()))
+    }
+}
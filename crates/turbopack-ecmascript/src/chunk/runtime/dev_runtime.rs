@@ -3,7 +3,9 @@ use std::io::Write;
 use anyhow::{bail, Context, Result};
 use indoc::writedoc;
 use serde::Serialize;
-use turbo_tasks::{primitives::StringVc, TryJoinIterExt, Value, ValueToString, ValueToStringVc};
+use turbo_tasks::{
+    primitives::StringVc, RcStr, TryJoinIterExt, Value, ValueToString, ValueToStringVc,
+};
 use turbo_tasks_fs::{embed_file, FileContent, FileSystemPathVc};
 use turbopack_core::{
     asset::Asset,
@@ -40,20 +42,72 @@ pub struct EcmascriptDevChunkRuntime {
 
 #[turbo_tasks::value_impl]
 impl EcmascriptDevChunkRuntimeVc {
+    /// Creates a new [`EcmascriptDevChunkRuntimeVc`].
+    ///
+    /// `ident`, when given, is used in place of `main_entry`'s ident to name
+    /// the chunk group instead. Passing a stable ident here keeps loader
+    /// chunk churn around `main_entry` from shifting `chunk_list_path`,
+    /// which would otherwise rename chunks and break HMR across reloads.
     #[turbo_tasks::function]
     pub fn new(
         chunking_context: ChunkingContextVc,
         main_entry: EcmascriptChunkPlaceableVc,
+        ident: Option<AssetIdentVc>,
     ) -> Self {
+        let naming_ident = ident.unwrap_or_else(|| main_entry.ident());
         EcmascriptDevChunkRuntime {
             chunking_context,
             chunk_group: None,
-            chunk_list_path: chunking_context.chunk_list_path(main_entry.ident()),
+            chunk_list_path: chunking_context.chunk_list_path(naming_ident),
         }
         .cell()
     }
 }
 
+/// The chunk list path, relative to `output_root`, formatted as a server
+/// path string. Cached per `(chunking_context, chunk_list_path)` pair so
+/// that [`EcmascriptChunkRuntime::decorate_asset_ident`] and
+/// [`EcmascriptChunkRuntime::params`] share the same computed string instead
+/// of each reformatting it.
+#[turbo_tasks::function]
+async fn chunk_list_server_path(
+    chunking_context: ChunkingContextVc,
+    chunk_list_path: FileSystemPathVc,
+) -> Result<StringVc> {
+    let output_root = chunking_context.output_root().await?;
+    let path = output_root
+        .get_path_to(&*chunk_list_path.await?)
+        .context("chunk list path is not in output root")?;
+    Ok(StringVc::cell(path.to_string()))
+}
+
+/// The server paths of every chunk in `chunk_group`, relative to
+/// `output_root`. Cached per `(chunking_context, chunk_group)` pair, which
+/// doesn't vary by origin chunk, so this list is computed once and cheaply
+/// cloned for every origin chunk's [`EcmascriptChunkRuntime::params`] call
+/// rather than rebuilt from scratch each time.
+#[turbo_tasks::value(transparent)]
+struct ChunkServerPaths(Vec<RcStr>);
+
+#[turbo_tasks::function]
+async fn chunk_group_server_paths(
+    chunking_context: ChunkingContextVc,
+    chunk_group: ChunkGroupVc,
+) -> Result<ChunkServerPathsVc> {
+    let output_root = chunking_context.output_root().await?;
+    let chunks = chunk_group.chunks().await?;
+
+    let mut paths = Vec::with_capacity(chunks.len());
+    for chunk in chunks.iter() {
+        let chunk_path = &*chunk.path().await?;
+        if let Some(chunk_server_path) = output_root.get_path_to(chunk_path) {
+            paths.push(RcStr::from(chunk_server_path));
+        }
+    }
+
+    Ok(ChunkServerPathsVc::cell(paths))
+}
+
 #[turbo_tasks::value_impl]
 impl ValueToString for EcmascriptDevChunkRuntime {
     #[turbo_tasks::function]
@@ -67,7 +121,7 @@ impl EcmascriptChunkRuntime for EcmascriptDevChunkRuntime {
     #[turbo_tasks::function]
     async fn decorate_asset_ident(&self, ident: AssetIdentVc) -> Result<AssetIdentVc> {
         let Self {
-            chunking_context: _,
+            chunking_context,
             chunk_group: _,
             chunk_list_path,
         } = self;
@@ -76,7 +130,7 @@ impl EcmascriptChunkRuntime for EcmascriptDevChunkRuntime {
 
         ident.add_modifier(StringVc::cell(format!(
             "chunk list {}",
-            chunk_list_path.to_string().await?
+            chunk_list_server_path(*chunking_context, *chunk_list_path).await?
         )));
 
         Ok(AssetIdentVc::new(Value::new(ident)))
@@ -117,9 +171,6 @@ impl EcmascriptChunkRuntime for EcmascriptDevChunkRuntime {
 
         let output_root = self.chunking_context.output_root().await?;
 
-        let evaluate_chunks = chunk_group.chunks().await?;
-        let mut chunk_dependencies = Vec::with_capacity(evaluate_chunks.len());
-
         let origin_chunk_path = origin_chunk.path().await?;
         let origin_chunk_path =
             if let Some(origin_chunk_path) = output_root.get_path_to(&*origin_chunk_path) {
@@ -131,14 +182,15 @@ impl EcmascriptChunkRuntime for EcmascriptDevChunkRuntime {
                 );
             };
 
-        for chunk in evaluate_chunks.iter() {
-            let chunk_path = &*chunk.path().await?;
-            if let Some(chunk_server_path) = output_root.get_path_to(chunk_path) {
-                if chunk_server_path != origin_chunk_path {
-                    chunk_dependencies.push(chunk_server_path.to_string());
-                }
-            }
-        }
+        // Shared across every origin chunk in this chunk group, so repeated
+        // paths are cheaply cloned here rather than reallocated per chunk.
+        let chunk_server_paths =
+            chunk_group_server_paths(self.chunking_context, chunk_group).await?;
+        let chunk_dependencies: Vec<RcStr> = chunk_server_paths
+            .iter()
+            .filter(|chunk_server_path| chunk_server_path.as_str() != origin_chunk_path)
+            .cloned()
+            .collect();
 
         let runtime_module_ids = origin_chunk
             .await?
@@ -149,10 +201,9 @@ impl EcmascriptChunkRuntime for EcmascriptDevChunkRuntime {
             .try_join()
             .await?;
 
-        let chunk_list_path = output_root
-            .get_path_to(&*self.chunk_list_path.await?)
-            .map(ToString::to_string)
-            .context("chunk list path is not in output root")?;
+        let chunk_list_path =
+            chunk_list_server_path(self.chunking_context, self.chunk_list_path).await?;
+        let chunk_list_path = RcStr::from(chunk_list_path.as_str());
 
         let params = EcmascriptDevChunkRuntimeParams {
             chunk_list_path,
@@ -194,6 +245,7 @@ impl EcmascriptChunkRuntime for EcmascriptDevChunkRuntime {
                 ChunkLoading::None => embed_file!("js/src/runtime.none.js").await?,
                 ChunkLoading::NodeJs => embed_file!("js/src/runtime.nodejs.js").await?,
                 ChunkLoading::Dom => embed_file!("js/src/runtime.dom.js").await?,
+                ChunkLoading::Worker => embed_file!("js/src/runtime.worker.js").await?,
             };
 
         match &*specific_runtime_code {
@@ -224,9 +276,9 @@ impl EcmascriptChunkRuntime for EcmascriptDevChunkRuntime {
 struct EcmascriptDevChunkRuntimeParams {
     /// List of chunk paths that this chunk depends on being loaded before it
     /// can be executed. Does not include the chunk itself.
-    chunk_dependencies: Vec<String>,
+    chunk_dependencies: Vec<RcStr>,
     /// List of module IDs that this chunk should instantiate when executed.
     runtime_module_ids: Vec<ModuleIdReadRef>,
     /// Path to the chunk list that this chunk should register itself with.
-    chunk_list_path: String,
+    chunk_list_path: RcStr,
 }
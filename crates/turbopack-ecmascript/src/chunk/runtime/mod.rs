@@ -1,5 +1,7 @@
+pub(crate) mod build_runtime;
 pub(crate) mod dev_runtime;
 
+pub use build_runtime::{EcmascriptBuildChunkRuntime, EcmascriptBuildChunkRuntimeVc};
 pub use dev_runtime::{EcmascriptDevChunkRuntime, EcmascriptDevChunkRuntimeVc};
 use turbo_tasks::{ValueToString, ValueToStringVc};
 use turbopack_core::{